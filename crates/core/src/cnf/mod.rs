@@ -1,7 +1,12 @@
+mod vars;
+
+use crate::err::Error;
 use crate::iam::file::extract_allowed_paths;
 use std::path::PathBuf;
 use std::sync::LazyLock;
 
+pub use vars::{FromVarValue, SessionVars, SessionVariableRow, Var, VarScope, VarValue};
+
 /// The characters which are supported in server record IDs.
 pub const ID_CHARS: [char; 36] = [
 	'0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
@@ -31,6 +36,29 @@ pub static MAX_OBJECT_PARSING_DEPTH: LazyLock<u32> =
 pub static MAX_QUERY_PARSING_DEPTH: LazyLock<u32> =
 	lazy_env_parse!("SURREAL_MAX_QUERY_PARSING_DEPTH", u32, 20);
 
+/// Specifies the maximum length, in bytes, allowed for a single string value (0 = unlimited).
+pub static MAX_STRING_LENGTH: LazyLock<usize> =
+	lazy_env_parse!("SURREAL_MAX_STRING_LENGTH", usize, 0);
+
+/// Specifies the maximum number of elements allowed in a single array value (0 = unlimited).
+pub static MAX_ARRAY_SIZE: LazyLock<usize> = lazy_env_parse!("SURREAL_MAX_ARRAY_SIZE", usize, 0);
+
+/// Specifies the maximum number of entries allowed in a single object value (0 = unlimited).
+pub static MAX_OBJECT_SIZE: LazyLock<usize> = lazy_env_parse!("SURREAL_MAX_OBJECT_SIZE", usize, 0);
+
+/// Specifies the maximum estimated total size, in bytes, of a single value, counted across all
+/// of its nested strings, arrays and objects (0 = unlimited).
+pub static MAX_VALUE_SIZE: LazyLock<usize> = lazy_env_parse!("SURREAL_MAX_VALUE_SIZE", usize, 0);
+
+/// Specifies the maximum number of operations (evaluated expressions, function calls and
+/// iterator steps) a single query may perform before it is aborted (0 = unlimited).
+pub static MAX_OPERATIONS: LazyLock<u64> = lazy_env_parse!("SURREAL_MAX_OPERATIONS", u64, 0);
+
+/// Specifies how many operations are allowed to elapse between checks of `MAX_OPERATIONS`, to
+/// keep the hot evaluation path cheap.
+pub static MAX_OPERATIONS_CHECK_INTERVAL: LazyLock<u64> =
+	lazy_env_parse!("SURREAL_MAX_OPERATIONS_CHECK_INTERVAL", u64, 100);
+
 /// Specifies the number of computed regexes which can be cached in the engine.
 pub static REGEX_CACHE_SIZE: LazyLock<usize> =
 	lazy_env_parse!("SURREAL_REGEX_CACHE_SIZE", usize, 1_000);
@@ -126,3 +154,354 @@ pub static FILE_ALLOWLIST: LazyLock<Vec<PathBuf>> = LazyLock::new(|| {
 		.map(|input| extract_allowed_paths(&input))
 		.unwrap_or_default()
 });
+
+/// The kind of value that tripped a [`MAX_STRING_LENGTH`], [`MAX_ARRAY_SIZE`],
+/// [`MAX_OBJECT_SIZE`] or [`MAX_VALUE_SIZE`] check.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValueSizeKind {
+	String,
+	Array,
+	Object,
+	Value,
+}
+
+impl std::fmt::Display for ValueSizeKind {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::String => write!(f, "string"),
+			Self::Array => write!(f, "array"),
+			Self::Object => write!(f, "object"),
+			Self::Value => write!(f, "value"),
+		}
+	}
+}
+
+/// Checks `len` against `SURREAL_MAX_STRING_LENGTH`, called when a string literal is built
+/// (`Value::strand_literal`) and on string concatenation.
+pub fn check_string_length(len: usize) -> Result<(), Error> {
+	check_size(ValueSizeKind::String, *MAX_STRING_LENGTH, len)
+}
+
+/// Checks `len` against `SURREAL_MAX_ARRAY_SIZE`, called when an array literal is built
+/// (`Array::from_literal`) and on `array::push` (`Array::push`).
+pub fn check_array_size(len: usize) -> Result<(), Error> {
+	check_size(ValueSizeKind::Array, *MAX_ARRAY_SIZE, len)
+}
+
+/// Checks `len` against `SURREAL_MAX_OBJECT_SIZE`, called when an object literal is built
+/// (`Object::from_literal`) and on field insertion (`Object::insert`).
+pub fn check_object_size(len: usize) -> Result<(), Error> {
+	check_size(ValueSizeKind::Object, *MAX_OBJECT_SIZE, len)
+}
+
+/// Checks a running byte estimate against `SURREAL_MAX_VALUE_SIZE`. The estimate should be
+/// maintained as a value is assembled, summing the byte size of every nested string plus a fixed
+/// per-element overhead for arrays and objects, so the aggregate limit bounds the total size of a
+/// deeply nested value and not just the size of any single level.
+pub fn check_value_size(estimate: usize) -> Result<(), Error> {
+	check_size(ValueSizeKind::Value, *MAX_VALUE_SIZE, estimate)
+}
+
+fn check_size(kind: ValueSizeKind, limit: usize, actual: usize) -> Result<(), Error> {
+	if limit > 0 && actual > limit {
+		return Err(Error::ValueTooLarge {
+			kind: kind.to_string(),
+			limit,
+			actual,
+		});
+	}
+	Ok(())
+}
+
+/// A fixed per-element overhead (in bytes) charged against `SURREAL_MAX_VALUE_SIZE` for every
+/// array element and object entry, so that a value made up of many small children is still
+/// bounded even though none of its individual strings are large.
+const VALUE_SIZE_ELEMENT_OVERHEAD: usize = 16;
+
+/// Accumulates the running byte estimate of a single [`Value`](crate::sql::Value) as it is
+/// assembled (parsed, or grown at runtime), checking it against `SURREAL_MAX_VALUE_SIZE` on every
+/// addition so that a deeply nested structure is bounded in total rather than just per level.
+///
+/// Array and object literal construction (`Array::from_literal`, `Object::from_literal`) and the
+/// array/object growth sites (`Array::push`, `Object::insert`) thread one through the whole
+/// resulting value, alongside the per-level [`check_array_size`] and [`check_object_size`]
+/// calls. String concatenation checks its result with [`check_value_size`] directly.
+#[derive(Debug)]
+pub struct ValueSizeEstimator {
+	total: usize,
+	limit: usize,
+}
+
+impl Default for ValueSizeEstimator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl ValueSizeEstimator {
+	pub fn new() -> Self {
+		Self {
+			total: 0,
+			limit: *MAX_VALUE_SIZE,
+		}
+	}
+
+	/// Builds an estimator against an explicit limit instead of `SURREAL_MAX_VALUE_SIZE`, for
+	/// testing and for callers (such as a session-scoped override) that need a limit other than
+	/// the process-wide default.
+	pub fn with_limit(limit: usize) -> Self {
+		Self {
+			total: 0,
+			limit,
+		}
+	}
+
+	/// The running byte estimate accumulated so far.
+	pub fn total(&self) -> usize {
+		self.total
+	}
+
+	/// Accounts for a string of `bytes` length being added to the value, e.g. a string literal, a
+	/// concatenation result, or an array/object key.
+	pub fn add_string(&mut self, bytes: usize) -> Result<(), Error> {
+		self.total = self.total.saturating_add(bytes);
+		check_size(ValueSizeKind::Value, self.limit, self.total)
+	}
+
+	/// Accounts for one additional array element or object entry being added to the value.
+	pub fn add_element(&mut self) -> Result<(), Error> {
+		self.total = self.total.saturating_add(VALUE_SIZE_ELEMENT_OVERHEAD);
+		check_size(ValueSizeKind::Value, self.limit, self.total)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn check_size_allows_unlimited() {
+		assert!(check_size(ValueSizeKind::String, 0, usize::MAX).is_ok());
+	}
+
+	#[test]
+	fn check_size_allows_within_limit() {
+		assert!(check_size(ValueSizeKind::String, 10, 10).is_ok());
+	}
+
+	#[test]
+	fn check_size_rejects_over_limit() {
+		let err = check_size(ValueSizeKind::Array, 10, 11).unwrap_err();
+		match err {
+			Error::ValueTooLarge {
+				kind,
+				limit,
+				actual,
+			} => {
+				assert_eq!(kind, "array");
+				assert_eq!(limit, 10);
+				assert_eq!(actual, 11);
+			}
+			other => panic!("expected Error::ValueTooLarge, got {other:?}"),
+		}
+	}
+
+	#[test]
+	fn value_size_estimator_trips_on_nested_strings() {
+		let mut est = ValueSizeEstimator::with_limit(20);
+		est.add_string(12).unwrap();
+		let err = est.add_string(12).unwrap_err();
+		assert!(matches!(err, Error::ValueTooLarge { .. }));
+	}
+
+	#[test]
+	fn value_size_estimator_trips_on_element_overhead() {
+		let mut est = ValueSizeEstimator::with_limit(VALUE_SIZE_ELEMENT_OVERHEAD);
+		est.add_element().unwrap();
+		let err = est.add_element().unwrap_err();
+		assert!(matches!(err, Error::ValueTooLarge { .. }));
+	}
+
+	#[test]
+	fn value_size_estimator_allows_unlimited() {
+		let mut est = ValueSizeEstimator::with_limit(0);
+		for _ in 0..1000 {
+			est.add_element().unwrap();
+		}
+	}
+
+	#[test]
+	fn operation_counter_allows_unlimited() {
+		let counter = OperationCounter::with_limits(0, 100);
+		for _ in 0..1000 {
+			counter.tick().unwrap();
+		}
+	}
+
+	#[test]
+	fn operation_counter_trips_once_over_limit() {
+		let counter = OperationCounter::with_limits(5, 1);
+		for _ in 0..5 {
+			counter.tick().unwrap();
+		}
+		let err = counter.tick().unwrap_err();
+		assert!(matches!(err, Error::QueryOperationsExceeded { limit: 5 }));
+	}
+
+	#[test]
+	fn operation_counter_zero_check_interval_does_not_panic() {
+		// A check interval of 0 must not be used as a modulus; it should behave like "check every
+		// operation" instead of panicking on the first tick.
+		let counter = OperationCounter::with_limits(2, 0);
+		counter.tick().unwrap();
+		counter.tick().unwrap();
+		let err = counter.tick().unwrap_err();
+		assert!(matches!(err, Error::QueryOperationsExceeded { limit: 2 }));
+	}
+
+	#[test]
+	fn checked_iter_counts_steps_and_trips() {
+		let counter = OperationCounter::with_limits(3, 1);
+		let results: Vec<_> = CheckedIter::new(0..10, &counter).collect();
+		assert_eq!(counter.count(), 10);
+		// 10 items, each individually checked (interval 1), plus one final exhaustion check.
+		assert_eq!(results.len(), 11);
+		assert!(results[..3].iter().all(|r| r.is_ok()));
+		assert!(results[3..].iter().all(|r| r.is_err()));
+	}
+
+	#[test]
+	fn checked_iter_catches_a_short_over_budget_query_on_exhaustion() {
+		// Mirrors the case a plain interval-boundary check misses: a query that finishes between
+		// two `check_interval` boundaries, but still blew past `limit`.
+		let counter = OperationCounter::with_limits(5, 100);
+		let results: Vec<_> = CheckedIter::new(0..90, &counter).collect();
+		assert_eq!(counter.count(), 90);
+		assert_eq!(results.len(), 91);
+		assert!(results[..90].iter().all(|r| r.is_ok()));
+		assert!(matches!(results[90], Err(Error::QueryOperationsExceeded { limit: 5 })));
+	}
+
+	#[test]
+	fn checked_iter_does_not_loop_forever_once_exhausted() {
+		let counter = OperationCounter::with_limits(0, 1);
+		let mut iter = CheckedIter::new(std::iter::empty::<()>(), &counter);
+		assert!(iter.next().is_none());
+		assert!(iter.next().is_none());
+	}
+}
+
+/// Tracks the number of operations (evaluated expressions, function calls, iterator steps)
+/// performed while executing a single query, aborting once `SURREAL_MAX_OPERATIONS` is exceeded.
+///
+/// The limit is only checked every `SURREAL_MAX_OPERATIONS_CHECK_INTERVAL` operations, so that
+/// incrementing the counter stays cheap on the hot evaluation path.
+#[derive(Debug)]
+pub struct OperationCounter {
+	count: std::sync::atomic::AtomicU64,
+	limit: u64,
+	// Guaranteed non-zero: a configured interval of 0 is treated as "check every operation",
+	// rather than being used as a modulus (which would panic).
+	check_interval: u64,
+}
+
+impl Default for OperationCounter {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl OperationCounter {
+	pub fn new() -> Self {
+		Self::with_limits(*MAX_OPERATIONS, *MAX_OPERATIONS_CHECK_INTERVAL)
+	}
+
+	/// Builds a counter against explicit limits instead of `SURREAL_MAX_OPERATIONS` /
+	/// `SURREAL_MAX_OPERATIONS_CHECK_INTERVAL`, for testing and for callers that need limits other
+	/// than the process-wide default (e.g. a session-scoped override).
+	pub fn with_limits(limit: u64, check_interval: u64) -> Self {
+		Self {
+			count: std::sync::atomic::AtomicU64::new(0),
+			limit,
+			check_interval: check_interval.max(1),
+		}
+	}
+
+	/// The number of operations recorded so far.
+	pub fn count(&self) -> u64 {
+		self.count.load(std::sync::atomic::Ordering::Relaxed)
+	}
+
+	/// Records one operation, returning an error once the configured ceiling has been exceeded.
+	///
+	/// The comparison against `limit` is only made every `check_interval` operations, to keep
+	/// this cheap on the hot path. A query that finishes between two interval boundaries must
+	/// still be checked once more via [`Self::finish`], since it may have blown past `limit`
+	/// without ever landing on a boundary.
+	pub fn tick(&self) -> Result<(), Error> {
+		let count = self.count.fetch_add(1, std::sync::atomic::Ordering::Relaxed) + 1;
+		if count % self.check_interval == 0 {
+			self.check(count)?;
+		}
+		Ok(())
+	}
+
+	/// Performs the unconditional final check, regardless of whether `count` lands on a
+	/// `check_interval` boundary. Call this once a query (or an iterator driving it) has
+	/// finished, so a short-but-over-budget query is still caught.
+	pub fn finish(&self) -> Result<(), Error> {
+		self.check(self.count())
+	}
+
+	fn check(&self, count: u64) -> Result<(), Error> {
+		if self.limit > 0 && count > self.limit {
+			return Err(Error::QueryOperationsExceeded {
+				limit: self.limit,
+			});
+		}
+		Ok(())
+	}
+}
+
+/// An iterator adaptor that charges one [`OperationCounter`] tick per step yielded, so that an
+/// iterator-driven part of execution (e.g. a cross-product over an array, or a function's
+/// internal loop) contributes to the per-query operation budget like any other evaluated
+/// expression.
+pub struct CheckedIter<'a, I> {
+	inner: I,
+	counter: &'a OperationCounter,
+	// Set once the inner iterator has been drained, so the unconditional final check below only
+	// ever yields one `Err` rather than looping forever if the budget is still exceeded.
+	exhausted: bool,
+}
+
+impl<'a, I> CheckedIter<'a, I> {
+	pub fn new(inner: I, counter: &'a OperationCounter) -> Self {
+		Self {
+			inner,
+			counter,
+			exhausted: false,
+		}
+	}
+}
+
+impl<'a, I: Iterator> Iterator for CheckedIter<'a, I> {
+	type Item = Result<I::Item, Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.exhausted {
+			return None;
+		}
+		let Some(item) = self.inner.next() else {
+			self.exhausted = true;
+			// The iterator is exhausted: perform the unconditional final check, so a query that
+			// finished between two `check_interval` boundaries is still caught if it blew past
+			// `SURREAL_MAX_OPERATIONS`.
+			return self.counter.finish().err().map(Err);
+		};
+		match self.counter.tick() {
+			Ok(()) => Some(Ok(item)),
+			Err(e) => Some(Err(e)),
+		}
+	}
+}
@@ -0,0 +1,251 @@
+//! A registry of runtime-tunable configuration knobs, alongside the env-only `cnf` statics.
+//!
+//! Every entry in the `cnf` module is read once from an environment variable at process start,
+//! so none of them can be adjusted without a restart, or scoped to a single connection. This
+//! module lets a subset of those knobs be described once, with a default sourced from the
+//! existing `cnf` statics, and then overridden per session via `SET <var> = <value>`.
+
+use crate::err::Error;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Where a [`Var`] may be set. Server-scoped variables can only be changed by restarting the
+/// process (today, via their backing environment variable); session-scoped variables may also be
+/// overridden for the lifetime of a single connection with `SET`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VarScope {
+	Session,
+	Server,
+}
+
+/// The value of a runtime-tunable variable, and the type it is validated against on `SET`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VarValue {
+	Usize(usize),
+	U32(u32),
+	Bool(bool),
+}
+
+impl VarValue {
+	fn type_name(&self) -> &'static str {
+		match self {
+			Self::Usize(_) => "number",
+			Self::U32(_) => "number",
+			Self::Bool(_) => "bool",
+		}
+	}
+}
+
+/// Converts a resolved [`VarValue`] into a concrete Rust type, backing the typed
+/// `ctx.var::<T>("name")` accessor on [`SessionVars`].
+pub trait FromVarValue: Sized {
+	fn from_var_value(v: VarValue) -> Option<Self>;
+}
+
+impl FromVarValue for usize {
+	fn from_var_value(v: VarValue) -> Option<Self> {
+		match v {
+			VarValue::Usize(n) => Some(n),
+			_ => None,
+		}
+	}
+}
+
+impl FromVarValue for u32 {
+	fn from_var_value(v: VarValue) -> Option<Self> {
+		match v {
+			VarValue::U32(n) => Some(n),
+			_ => None,
+		}
+	}
+}
+
+impl FromVarValue for bool {
+	fn from_var_value(v: VarValue) -> Option<Self> {
+		match v {
+			VarValue::Bool(b) => Some(b),
+			_ => None,
+		}
+	}
+}
+
+/// Describes a single runtime-tunable variable: its name, a human-readable description, the
+/// scope it may be set at, and the default value sourced from the matching `cnf` static.
+pub struct Var {
+	pub name: &'static str,
+	pub description: &'static str,
+	pub scope: VarScope,
+	pub default: fn() -> VarValue,
+}
+
+/// The set of variables which can currently be read through the registry, keeping the existing
+/// `cnf` env vars as their server default so existing deployments are unaffected.
+pub static REGISTRY: LazyLock<Vec<Var>> = LazyLock::new(|| {
+	vec![
+		Var {
+			name: "normal_fetch_size",
+			description: "The maximum number of keys that should be scanned at once in general queries.",
+			scope: VarScope::Session,
+			default: || VarValue::U32(*super::NORMAL_FETCH_SIZE),
+		},
+		Var {
+			name: "regex_cache_size",
+			description: "The number of computed regexes which can be cached in the engine.",
+			scope: VarScope::Server,
+			default: || VarValue::Usize(*super::REGEX_CACHE_SIZE),
+		},
+		Var {
+			name: "transaction_cache_size",
+			description: "The number of items which can be cached within a single transaction.",
+			scope: VarScope::Server,
+			default: || VarValue::Usize(*super::TRANSACTION_CACHE_SIZE),
+		},
+		Var {
+			name: "max_computation_depth",
+			description: "How deep computation recursive calls will go before an error is returned.",
+			scope: VarScope::Session,
+			default: || VarValue::U32(*super::MAX_COMPUTATION_DEPTH),
+		},
+		Var {
+			name: "insecure_forward_access_errors",
+			description: "Forward all signup/signin/authenticate query errors to a client performing authentication.",
+			scope: VarScope::Session,
+			default: || VarValue::Bool(*super::INSECURE_FORWARD_ACCESS_ERRORS),
+		},
+	]
+});
+
+fn lookup(name: &str) -> Option<&'static Var> {
+	REGISTRY.iter().find(|v| v.name == name)
+}
+
+/// A per-session table of variable overrides, falling back to the server default from
+/// [`REGISTRY`] for anything that has not been explicitly `SET`.
+#[derive(Clone, Debug, Default)]
+pub struct SessionVars {
+	overrides: HashMap<String, VarValue>,
+}
+
+impl SessionVars {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Resolves a variable by checking the per-session override first, falling back to the
+	/// server default. Returns `None` if no variable with this name is registered.
+	pub fn get(&self, name: &str) -> Option<VarValue> {
+		if let Some(v) = self.overrides.get(name) {
+			return Some(v.clone());
+		}
+		lookup(name).map(|v| (v.default)())
+	}
+
+	/// Typed accessor mirroring the engine read site `ctx.var::<u32>("normal_fetch_size")`:
+	/// resolves the variable through [`SessionVars::get`] and converts it to `T`, returning `None`
+	/// if the variable is unknown or registered under a different type.
+	pub fn var<T: FromVarValue>(&self, name: &str) -> Option<T> {
+		self.get(name).and_then(T::from_var_value)
+	}
+
+	/// Implements `SET <var> = <value>`, validating that the variable exists, is settable at
+	/// session scope, and that `value` matches its existing type.
+	pub fn set(&mut self, name: &str, value: VarValue) -> Result<(), Error> {
+		let var = lookup(name).ok_or_else(|| Error::UnknownSessionVariable(name.to_owned()))?;
+		if var.scope != VarScope::Session {
+			return Err(Error::SessionVariableNotSettable(name.to_owned()));
+		}
+		let current = (var.default)();
+		if std::mem::discriminant(&current) != std::mem::discriminant(&value) {
+			return Err(Error::InvalidSessionVariableValue {
+				name: name.to_owned(),
+				expected: current.type_name().to_owned(),
+			});
+		}
+		self.overrides.insert(name.to_owned(), value);
+		Ok(())
+	}
+
+	/// Lists every registered variable together with its currently resolved value, backing the
+	/// `session_variables` introspection table.
+	pub fn describe_all(&self) -> Vec<SessionVariableRow> {
+		REGISTRY
+			.iter()
+			.map(|v| SessionVariableRow {
+				name: v.name,
+				description: v.description,
+				scope: v.scope,
+				value: self.get(v.name).expect("registered variable"),
+			})
+			.collect()
+	}
+}
+
+/// One row of the `session_variables` introspection table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionVariableRow {
+	pub name: &'static str,
+	pub description: &'static str,
+	pub scope: VarScope,
+	pub value: VarValue,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn get_falls_back_to_server_default() {
+		let vars = SessionVars::new();
+		assert_eq!(vars.get("normal_fetch_size"), Some(VarValue::U32(*super::super::NORMAL_FETCH_SIZE)));
+	}
+
+	#[test]
+	fn get_returns_none_for_unknown_variable() {
+		let vars = SessionVars::new();
+		assert_eq!(vars.get("does_not_exist"), None);
+	}
+
+	#[test]
+	fn set_then_get_returns_override() {
+		let mut vars = SessionVars::new();
+		vars.set("normal_fetch_size", VarValue::U32(500)).unwrap();
+		assert_eq!(vars.get("normal_fetch_size"), Some(VarValue::U32(500)));
+	}
+
+	#[test]
+	fn set_rejects_unknown_variable() {
+		let mut vars = SessionVars::new();
+		let err = vars.set("does_not_exist", VarValue::Bool(true)).unwrap_err();
+		assert!(matches!(err, Error::UnknownSessionVariable(_)));
+	}
+
+	#[test]
+	fn set_rejects_server_scoped_variable() {
+		let mut vars = SessionVars::new();
+		let err = vars.set("regex_cache_size", VarValue::Usize(5)).unwrap_err();
+		assert!(matches!(err, Error::SessionVariableNotSettable(_)));
+	}
+
+	#[test]
+	fn set_rejects_mismatched_type() {
+		let mut vars = SessionVars::new();
+		let err = vars.set("normal_fetch_size", VarValue::Bool(true)).unwrap_err();
+		assert!(matches!(err, Error::InvalidSessionVariableValue { .. }));
+	}
+
+	#[test]
+	fn typed_accessor_resolves_override() {
+		let mut vars = SessionVars::new();
+		vars.set("max_computation_depth", VarValue::U32(42)).unwrap();
+		assert_eq!(vars.var::<u32>("max_computation_depth"), Some(42));
+		assert_eq!(vars.var::<bool>("max_computation_depth"), None);
+	}
+
+	#[test]
+	fn describe_all_lists_every_registered_variable() {
+		let vars = SessionVars::new();
+		let rows = vars.describe_all();
+		assert_eq!(rows.len(), REGISTRY.len());
+		assert!(rows.iter().any(|r| r.name == "normal_fetch_size"));
+	}
+}
@@ -0,0 +1,120 @@
+use crate::cnf;
+use crate::err::Error;
+use crate::sql::value::Value;
+
+/// The per-query execution context: the state threaded through expression evaluation, function
+/// calls and iterators for a single query.
+pub struct Context {
+	operations: cnf::OperationCounter,
+	session_vars: cnf::SessionVars,
+}
+
+impl Default for Context {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Context {
+	pub fn new() -> Self {
+		Self {
+			operations: cnf::OperationCounter::new(),
+			session_vars: cnf::SessionVars::new(),
+		}
+	}
+
+	/// The typed engine read site for a runtime-tunable variable, e.g.
+	/// `ctx.var::<u32>("normal_fetch_size")`, resolved through the session-variable registry
+	/// instead of dereferencing the matching `cnf` `LazyLock` directly.
+	pub fn var<T: cnf::FromVarValue>(&self, name: &str) -> Option<T> {
+		self.session_vars.var(name)
+	}
+
+	/// The untyped resolution of a variable, backing `SHOW <var>`.
+	pub fn var_value(&self, name: &str) -> Option<cnf::VarValue> {
+		self.session_vars.get(name)
+	}
+
+	/// Implements `SET <var> = <value>` for this connection.
+	pub fn set_var(&mut self, name: &str, value: cnf::VarValue) -> Result<(), Error> {
+		self.session_vars.set(name, value)
+	}
+
+	/// Backs the `session_variables` introspection table: every registered variable together with
+	/// its value as resolved for this session.
+	pub fn session_variables(&self) -> Vec<cnf::SessionVariableRow> {
+		self.session_vars.describe_all()
+	}
+
+	/// The number of keys that should be scanned at once in general queries, read through the
+	/// session-variable registry so it can be overridden per connection with
+	/// `SET normal_fetch_size = ...`, falling back to `SURREAL_NORMAL_FETCH_SIZE` otherwise. This
+	/// replaces a direct `*cnf::NORMAL_FETCH_SIZE` dereference at the engine read site.
+	pub fn normal_fetch_size(&self) -> u32 {
+		self.var::<u32>("normal_fetch_size").unwrap_or(*cnf::NORMAL_FETCH_SIZE)
+	}
+
+	/// Records one operation (an evaluated expression, function call, or iterator step),
+	/// returning an error once `SURREAL_MAX_OPERATIONS` has been exceeded.
+	pub fn tick_operation(&self) -> Result<(), Error> {
+		self.operations.tick()
+	}
+
+	/// Wraps `iter` so that pulling each item charges one operation against this context's
+	/// budget, for iterator-driven evaluation (e.g. a cross-product over an array).
+	pub fn checked_iter<I: Iterator>(&self, iter: I) -> cnf::CheckedIter<'_, I> {
+		cnf::CheckedIter::new(iter, &self.operations)
+	}
+
+	/// Evaluates an array literal's elements, charging one operation per element (mirroring how
+	/// evaluating any other expression charges an operation) before handing the collected items
+	/// to [`crate::sql::array::Array::from_literal`] for the `SURREAL_MAX_ARRAY_SIZE`/
+	/// `SURREAL_MAX_VALUE_SIZE` checks.
+	pub fn eval_array_literal(&self, items: Vec<Value>) -> Result<Value, Error> {
+		for step in self.checked_iter(items.iter()) {
+			step?;
+		}
+		Ok(Value::Array(crate::sql::array::Array::from_literal(items)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn eval_array_literal_ticks_an_operation_per_element() {
+		let ctx = Context::new();
+		let v = ctx.eval_array_literal(vec![Value::Bool(true), Value::None]).unwrap();
+		assert_eq!(v, Value::Array(crate::sql::array::Array(vec![Value::Bool(true), Value::None])));
+		assert_eq!(ctx.operations.count(), 2);
+	}
+
+	#[test]
+	fn tick_operation_is_reachable_from_the_context() {
+		let ctx = Context::new();
+		ctx.tick_operation().unwrap();
+		ctx.tick_operation().unwrap();
+	}
+
+	#[test]
+	fn normal_fetch_size_falls_back_to_the_server_default() {
+		let ctx = Context::new();
+		assert_eq!(ctx.normal_fetch_size(), *cnf::NORMAL_FETCH_SIZE);
+	}
+
+	#[test]
+	fn set_var_overrides_the_engine_read_site() {
+		let mut ctx = Context::new();
+		ctx.set_var("normal_fetch_size", cnf::VarValue::U32(9_999)).unwrap();
+		assert_eq!(ctx.normal_fetch_size(), 9_999);
+		assert_eq!(ctx.var::<u32>("normal_fetch_size"), Some(9_999));
+	}
+
+	#[test]
+	fn session_variables_lists_every_registered_variable() {
+		let ctx = Context::new();
+		let rows = ctx.session_variables();
+		assert!(rows.iter().any(|r| r.name == "normal_fetch_size"));
+	}
+}
@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+/// The error type returned by fallible operations across the core crate.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+	#[error("there was an arithmetic error: {0}")]
+	ArithmeticNegativeOverflow(String),
+
+	#[error("the {kind} exceeded the configured size limit of {limit} (was {actual})")]
+	ValueTooLarge {
+		kind: String,
+		limit: usize,
+		actual: usize,
+	},
+
+	#[error("the query exceeded the configured operation limit of {limit}")]
+	QueryOperationsExceeded {
+		limit: u64,
+	},
+
+	#[error("'{0}' is not a known session variable")]
+	UnknownSessionVariable(String),
+
+	#[error("'{0}' is a server-scoped variable and cannot be set for a session")]
+	SessionVariableNotSettable(String),
+
+	#[error("invalid value for session variable '{name}': expected a {expected}")]
+	InvalidSessionVariableValue {
+		name: String,
+		expected: String,
+	},
+
+	#[error("'{0}' is not a valid timezone")]
+	InvalidTimezone(String),
+}
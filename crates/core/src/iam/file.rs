@@ -0,0 +1,6 @@
+use std::path::PathBuf;
+
+/// Parses a comma-separated `SURREAL_FILE_ALLOWLIST` value into a list of allowed paths.
+pub fn extract_allowed_paths(input: &str) -> Vec<PathBuf> {
+	input.split(',').map(str::trim).filter(|s| !s.is_empty()).map(PathBuf::from).collect()
+}
@@ -0,0 +1,6 @@
+pub mod cnf;
+pub mod ctx;
+pub mod err;
+pub mod iam;
+pub mod sql;
+pub mod syn;
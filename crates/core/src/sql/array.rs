@@ -0,0 +1,61 @@
+use crate::cnf;
+use crate::err::Error;
+use crate::sql::value::Value;
+
+/// An array value in SurrealQL.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Array(pub Vec<Value>);
+
+impl Array {
+	/// Builds an array from parsed literal elements, checked against `SURREAL_MAX_ARRAY_SIZE` and
+	/// `SURREAL_MAX_VALUE_SIZE` at the point the literal is constructed by the parser.
+	pub fn from_literal(items: Vec<Value>) -> Result<Self, Error> {
+		cnf::check_array_size(items.len())?;
+		let mut estimator = cnf::ValueSizeEstimator::new();
+		for item in &items {
+			estimator.add_element()?;
+			item.accumulate_size(&mut estimator)?;
+		}
+		Ok(Self(items))
+	}
+
+	/// Implements `array::push`: appends `value`, re-checking `SURREAL_MAX_ARRAY_SIZE` and the
+	/// estimated total size of the resulting array against `SURREAL_MAX_VALUE_SIZE` before it is
+	/// allowed to grow the array.
+	pub fn push(&mut self, value: Value) -> Result<(), Error> {
+		cnf::check_array_size(self.0.len() + 1)?;
+		let mut estimator = cnf::ValueSizeEstimator::new();
+		for item in self.0.iter().chain(std::iter::once(&value)) {
+			estimator.add_element()?;
+			item.accumulate_size(&mut estimator)?;
+		}
+		self.0.push(value);
+		Ok(())
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn from_literal_builds_an_array() {
+		let arr = Array::from_literal(vec![Value::Bool(true), Value::None]).unwrap();
+		assert_eq!(arr.len(), 2);
+	}
+
+	#[test]
+	fn push_grows_the_array() {
+		let mut arr = Array::from_literal(vec![]).unwrap();
+		arr.push(Value::Bool(false)).unwrap();
+		assert_eq!(arr.len(), 1);
+	}
+}
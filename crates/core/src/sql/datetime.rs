@@ -2,7 +2,8 @@ use crate::err::Error;
 use crate::sql::duration::Duration;
 use crate::sql::strand::Strand;
 use crate::syn;
-use chrono::{offset::LocalResult, DateTime, SecondsFormat, TimeZone, Utc};
+use chrono::{offset::LocalResult, DateTime, FixedOffset, Offset, SecondsFormat, TimeZone, Utc};
+use chrono_tz::Tz;
 use revision::revisioned;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
@@ -16,26 +17,68 @@ use super::value::TrySub;
 
 pub(crate) const TOKEN: &str = "$surrealdb::private::sql::Datetime";
 
+/// Either a named IANA zone or a fixed UTC offset, kept alongside a [`Datetime`]'s instant so the
+/// original zone of an ingested timestamp survives a round-trip instead of always being
+/// normalized to UTC.
+#[revisioned(revision = 1)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Zone {
+	Named(Tz),
+	Fixed(FixedOffset),
+}
+
+impl Zone {
+	/// Looks up a named IANA zone (e.g. `"Asia/Tokyo"`), the path used by `time::with_zone` to
+	/// produce a [`Zone::Named`] from a zone name given at runtime.
+	pub fn named(name: &str) -> Result<Self, Error> {
+		name.parse::<Tz>().map(Self::Named).map_err(|_| Error::InvalidTimezone(name.to_owned()))
+	}
+
+	fn to_offset(self, instant: &DateTime<Utc>) -> FixedOffset {
+		match self {
+			Self::Named(tz) => instant.with_timezone(&tz).offset().fix(),
+			Self::Fixed(offset) => offset,
+		}
+	}
+}
+
+impl Display for Zone {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Named(tz) => write!(f, "{tz}"),
+			Self::Fixed(offset) => write!(f, "{offset}"),
+		}
+	}
+}
+
+/// A datetime value in SurrealQL.
+///
+/// The instant is always kept normalized to UTC, so arithmetic and comparisons stay correct
+/// across zones. Alongside it, an optional [`Zone`] records the zone the value was parsed with
+/// (or was last set to via `time::with_zone`), so that zone survives a round-trip through
+/// `to_raw`/`Display` instead of always being rendered as `Z`. A missing zone means UTC, which
+/// keeps the wire/stored representation backward compatible with values that predate zone
+/// tracking.
 #[revisioned(revision = 1)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize, Hash)]
 #[serde(rename = "$surrealdb::private::sql::Datetime")]
 #[non_exhaustive]
-pub struct Datetime(pub DateTime<Utc>);
+pub struct Datetime(pub DateTime<Utc>, #[revision(start = 2)] pub(crate) Option<Zone>);
 
 impl Datetime {
-	pub const MIN_UTC: Self = Datetime(DateTime::<Utc>::MIN_UTC);
-	pub const MAX_UTC: Self = Datetime(DateTime::<Utc>::MAX_UTC);
+	pub const MIN_UTC: Self = Datetime(DateTime::<Utc>::MIN_UTC, None);
+	pub const MAX_UTC: Self = Datetime(DateTime::<Utc>::MAX_UTC, None);
 }
 
 impl Default for Datetime {
 	fn default() -> Self {
-		Self(Utc::now())
+		Self(Utc::now(), None)
 	}
 }
 
 impl From<DateTime<Utc>> for Datetime {
 	fn from(v: DateTime<Utc>) -> Self {
-		Self(v)
+		Self(v, None)
 	}
 }
 
@@ -69,6 +112,21 @@ impl TryFrom<Strand> for Datetime {
 impl TryFrom<&str> for Datetime {
 	type Error = ();
 	fn try_from(v: &str) -> Result<Self, Self::Error> {
+		// Parse with `DateTime::parse_from_rfc3339` first so the original offset is retained; fall
+		// back to `syn::datetime`, which applies the same RFC 3339 parse, so any other input is
+		// rejected.
+		if let Ok(parsed) = DateTime::parse_from_rfc3339(v) {
+			// A zero offset is ambiguous between an explicit `+00:00` and a bare `Z` (which chrono
+			// also parses to a zero `FixedOffset`). Treat it as the latter so a plain UTC literal
+			// keeps round-tripping as `Z` through `to_raw`, rather than gaining a spurious
+			// `+00:00` zone.
+			let zone = if parsed.offset().local_minus_utc() == 0 {
+				None
+			} else {
+				Some(Zone::Fixed(*parsed.offset()))
+			};
+			return Ok(Self(parsed.with_timezone(&Utc), zone));
+		}
 		match syn::datetime(v) {
 			Ok(v) => Ok(v),
 			_ => Err(()),
@@ -80,7 +138,7 @@ impl TryFrom<(i64, u32)> for Datetime {
 	type Error = ();
 	fn try_from(v: (i64, u32)) -> Result<Self, Self::Error> {
 		match Utc.timestamp_opt(v.0, v.1) {
-			LocalResult::Single(v) => Ok(Self(v)),
+			LocalResult::Single(v) => Ok(Self(v, None)),
 			_ => Err(()),
 		}
 	}
@@ -94,9 +152,27 @@ impl Deref for Datetime {
 }
 
 impl Datetime {
-	/// Convert the Datetime to a raw String
+	/// The zone this value was parsed with, or last set to via [`Datetime::with_zone`]. `None`
+	/// means UTC (rendered as a bare `Z`).
+	pub fn zone(&self) -> Option<Zone> {
+		self.1
+	}
+
+	/// Returns a copy of this value rendered in `zone` instead of its current one. The underlying
+	/// instant is unchanged, so this only affects `to_raw`/`Display` and zone-aware formatting.
+	pub fn with_zone(&self, zone: Zone) -> Self {
+		Self(self.0, Some(zone))
+	}
+
+	/// Convert the Datetime to a raw String, preserving the attached zone's offset (if any)
+	/// instead of always rendering `Z`.
 	pub fn to_raw(&self) -> String {
-		self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+		match self.1 {
+			Some(zone) => {
+				self.0.with_timezone(&zone.to_offset(&self.0)).to_rfc3339_opts(SecondsFormat::AutoSi, false)
+			}
+			None => self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+		}
 	}
 
 	/// Convert to nanosecond timestamp.
@@ -130,3 +206,100 @@ impl TrySub for Datetime {
 			.map(Duration::from)
 	}
 }
+
+/// Implements `time::zone`: the name of the zone `dt` was parsed with or last set to, or `"UTC"`
+/// for a value with no zone attached.
+pub fn time_zone(dt: &Datetime) -> String {
+	match dt.zone() {
+		Some(zone) => zone.to_string(),
+		None => "UTC".to_owned(),
+	}
+}
+
+/// Implements `time::with_zone`: returns `dt` rendered in the named IANA zone instead of its
+/// current one, without changing the instant it represents.
+pub fn time_with_zone(dt: &Datetime, zone_name: &str) -> Result<Datetime, Error> {
+	Ok(dt.with_zone(Zone::named(zone_name)?))
+}
+
+/// Implements `time::format`: renders `dt` with a `chrono` strftime-style format string, using the
+/// zone attached to `dt` (or UTC, if none) rather than always formatting in UTC.
+pub fn time_format(dt: &Datetime, format: &str) -> String {
+	match dt.zone() {
+		Some(zone) => dt.0.with_timezone(&zone.to_offset(&dt.0)).format(format).to_string(),
+		None => dt.0.format(format).to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bare_z_round_trips_as_utc() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		assert_eq!(dt.zone(), None);
+		assert_eq!(dt.to_raw(), "2023-05-01T12:30:00Z");
+	}
+
+	#[test]
+	fn explicit_zero_offset_also_round_trips_as_utc() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00+00:00").unwrap();
+		assert_eq!(dt.zone(), None);
+		assert_eq!(dt.to_raw(), "2023-05-01T12:30:00Z");
+	}
+
+	#[test]
+	fn non_zero_offset_is_preserved() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00+09:00").unwrap();
+		assert_eq!(dt.zone(), Some(Zone::Fixed(FixedOffset::east_opt(9 * 3600).unwrap())));
+		assert_eq!(dt.to_raw(), "2023-05-01T12:30:00+09:00");
+	}
+
+	#[test]
+	fn with_zone_produces_a_named_zone() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		let tokyo = time_with_zone(&dt, "Asia/Tokyo").unwrap();
+		assert!(matches!(tokyo.zone(), Some(Zone::Named(_))));
+		assert_eq!(time_zone(&tokyo), "Asia/Tokyo");
+		assert_eq!(tokyo.to_raw(), "2023-05-01T21:30:00+09:00");
+	}
+
+	#[test]
+	fn with_zone_rejects_unknown_name() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		assert!(time_with_zone(&dt, "Not/AZone").is_err());
+	}
+
+	#[test]
+	fn time_zone_reports_utc_for_no_zone() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		assert_eq!(time_zone(&dt), "UTC");
+	}
+
+	#[test]
+	fn time_format_uses_the_attached_zone() {
+		let dt = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		let tokyo = time_with_zone(&dt, "Asia/Tokyo").unwrap();
+		assert_eq!(time_format(&tokyo, "%H:%M"), "21:30");
+		assert_eq!(time_format(&dt, "%H:%M"), "12:30");
+	}
+
+	#[test]
+	fn subtraction_is_correct_across_zones() {
+		let utc = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		let tokyo = Datetime::try_from("2023-05-01T21:30:00+09:00").unwrap();
+		assert_eq!(utc.clone() - tokyo.clone(), Duration::default());
+		assert_eq!(utc.try_sub(tokyo).unwrap(), Duration::default());
+	}
+
+	#[test]
+	fn zone_affects_equality_but_not_the_instant() {
+		// Two values parsed from the same instant in different zones are not `==` (Zone is part
+		// of the derived PartialEq), but their arithmetic is unaffected by which zone is attached.
+		let utc = Datetime::try_from("2023-05-01T12:30:00Z").unwrap();
+		let tokyo = Datetime::try_from("2023-05-01T21:30:00+09:00").unwrap();
+		assert_ne!(utc, tokyo);
+		assert_eq!(utc.0, tokyo.0);
+	}
+}
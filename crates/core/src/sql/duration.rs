@@ -0,0 +1,18 @@
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration as StdDuration;
+
+/// A duration of time in SurrealQL.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Duration(pub StdDuration);
+
+impl From<StdDuration> for Duration {
+	fn from(v: StdDuration) -> Self {
+		Self(v)
+	}
+}
+
+impl Display for Duration {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{:?}", self.0)
+	}
+}
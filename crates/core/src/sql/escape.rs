@@ -0,0 +1,10 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Renders a string as a single-quoted SurrealQL literal.
+pub struct QuoteStr<'a>(pub &'a str);
+
+impl Display for QuoteStr<'_> {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "'{}'", self.0.replace('\'', "\\'"))
+	}
+}
@@ -0,0 +1,15 @@
+pub mod array;
+pub mod datetime;
+pub mod duration;
+pub mod escape;
+pub mod object;
+pub mod statements;
+pub mod strand;
+pub mod value;
+
+pub use array::Array;
+pub use datetime::Datetime;
+pub use duration::Duration;
+pub use object::Object;
+pub use strand::Strand;
+pub use value::Value;
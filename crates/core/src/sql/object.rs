@@ -0,0 +1,74 @@
+use crate::cnf;
+use crate::err::Error;
+use crate::sql::value::Value;
+use std::collections::BTreeMap;
+
+/// An object value in SurrealQL.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Object(pub BTreeMap<String, Value>);
+
+impl Object {
+	/// Builds an object from parsed literal entries, checked against `SURREAL_MAX_OBJECT_SIZE` and
+	/// `SURREAL_MAX_VALUE_SIZE` at the point the literal is constructed by the parser.
+	pub fn from_literal(entries: BTreeMap<String, Value>) -> Result<Self, Error> {
+		cnf::check_object_size(entries.len())?;
+		let mut estimator = cnf::ValueSizeEstimator::new();
+		for (key, value) in &entries {
+			estimator.add_element()?;
+			estimator.add_string(key.len())?;
+			value.accumulate_size(&mut estimator)?;
+		}
+		Ok(Self(entries))
+	}
+
+	/// Implements object field insertion (e.g. `UPDATE ... SET obj.field = value`): re-checks
+	/// `SURREAL_MAX_OBJECT_SIZE` and the estimated total size of the resulting object against
+	/// `SURREAL_MAX_VALUE_SIZE` before it is allowed to grow the object.
+	pub fn insert(&mut self, key: String, value: Value) -> Result<(), Error> {
+		let len = if self.0.contains_key(&key) {
+			self.0.len()
+		} else {
+			self.0.len() + 1
+		};
+		cnf::check_object_size(len)?;
+		let mut estimator = cnf::ValueSizeEstimator::new();
+		for (k, v) in self.0.iter().filter(|(k, _)| **k != key) {
+			estimator.add_element()?;
+			estimator.add_string(k.len())?;
+			v.accumulate_size(&mut estimator)?;
+		}
+		estimator.add_element()?;
+		estimator.add_string(key.len())?;
+		value.accumulate_size(&mut estimator)?;
+		self.0.insert(key, value);
+		Ok(())
+	}
+
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn insert_grows_the_object() {
+		let mut obj = Object::from_literal(BTreeMap::new()).unwrap();
+		obj.insert("a".to_owned(), Value::Bool(true)).unwrap();
+		assert_eq!(obj.len(), 1);
+	}
+
+	#[test]
+	fn insert_does_not_grow_on_overwrite() {
+		let mut obj = Object::from_literal(BTreeMap::new()).unwrap();
+		obj.insert("a".to_owned(), Value::Bool(true)).unwrap();
+		obj.insert("a".to_owned(), Value::Bool(false)).unwrap();
+		assert_eq!(obj.len(), 1);
+	}
+}
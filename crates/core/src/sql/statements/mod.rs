@@ -0,0 +1,3 @@
+pub mod set;
+
+pub use set::{SetStatement, ShowStatement};
@@ -0,0 +1,62 @@
+use crate::cnf::VarValue;
+use crate::ctx::Context;
+use crate::err::Error;
+
+/// `SET <var> = <value>`: overrides a session-scoped runtime variable, from the registry added in
+/// `crate::cnf::vars`, for the lifetime of the current connection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetStatement {
+	pub name: String,
+	pub value: VarValue,
+}
+
+impl SetStatement {
+	pub fn compute(&self, ctx: &mut Context) -> Result<(), Error> {
+		ctx.set_var(&self.name, self.value.clone())
+	}
+}
+
+/// `SHOW <var>`: returns the currently resolved value of a single runtime variable.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowStatement {
+	pub name: String,
+}
+
+impl ShowStatement {
+	pub fn compute(&self, ctx: &Context) -> Result<VarValue, Error> {
+		ctx.var_value(&self.name).ok_or_else(|| Error::UnknownSessionVariable(self.name.clone()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn set_then_show_round_trips() {
+		let mut ctx = Context::new();
+		SetStatement {
+			name: "normal_fetch_size".to_owned(),
+			value: VarValue::U32(7),
+		}
+		.compute(&mut ctx)
+		.unwrap();
+		let shown = ShowStatement {
+			name: "normal_fetch_size".to_owned(),
+		}
+		.compute(&ctx)
+		.unwrap();
+		assert_eq!(shown, VarValue::U32(7));
+	}
+
+	#[test]
+	fn show_rejects_unknown_variable() {
+		let ctx = Context::new();
+		let err = ShowStatement {
+			name: "does_not_exist".to_owned(),
+		}
+		.compute(&ctx)
+		.unwrap_err();
+		assert!(matches!(err, Error::UnknownSessionVariable(_)));
+	}
+}
@@ -0,0 +1,37 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::Deref;
+
+/// A string value in SurrealQL.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Strand(pub String);
+
+impl Strand {
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+impl Deref for Strand {
+	type Target = str;
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl From<String> for Strand {
+	fn from(v: String) -> Self {
+		Self(v)
+	}
+}
+
+impl From<&str> for Strand {
+	fn from(v: &str) -> Self {
+		Self(v.to_owned())
+	}
+}
+
+impl Display for Strand {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
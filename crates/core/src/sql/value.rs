@@ -0,0 +1,117 @@
+use crate::cnf;
+use crate::err::Error;
+use crate::sql::array::Array;
+use crate::sql::datetime::Datetime;
+use crate::sql::object::Object;
+use crate::sql::strand::Strand;
+use std::ops;
+
+/// A computed value in SurrealQL.
+///
+/// This only lists the variants needed to enforce the `SURREAL_MAX_STRING_LENGTH`,
+/// `SURREAL_MAX_ARRAY_SIZE`, `SURREAL_MAX_OBJECT_SIZE` and `SURREAL_MAX_VALUE_SIZE` limits at
+/// literal-construction and growth time.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+	None,
+	Bool(bool),
+	Strand(Strand),
+	Array(Array),
+	Object(Object),
+	Datetime(Datetime),
+}
+
+/// Fallible subtraction, used by datetime arithmetic where the operands may underflow.
+pub trait TrySub<Rhs = Self> {
+	type Output;
+	fn try_sub(self, other: Rhs) -> Result<Self::Output, Error>;
+}
+
+impl Value {
+	/// Builds a string literal, checked against `SURREAL_MAX_STRING_LENGTH` at the point the
+	/// parser constructs it.
+	pub fn strand_literal(s: String) -> Result<Self, Error> {
+		cnf::check_string_length(s.len())?;
+		Ok(Self::Strand(Strand(s)))
+	}
+
+	/// Adds this running byte/element contribution to `estimator`, checking the aggregate
+	/// `SURREAL_MAX_VALUE_SIZE` limit as the value is assembled. Called recursively so that a
+	/// deeply nested value is bounded in total, not just per level.
+	pub fn accumulate_size(&self, estimator: &mut cnf::ValueSizeEstimator) -> Result<(), Error> {
+		match self {
+			Self::None | Self::Bool(_) => Ok(()),
+			Self::Strand(s) => estimator.add_string(s.as_str().len()),
+			Self::Datetime(_) => estimator.add_element(),
+			Self::Array(a) => {
+				for item in &a.0 {
+					estimator.add_element()?;
+					item.accumulate_size(estimator)?;
+				}
+				Ok(())
+			}
+			Self::Object(o) => {
+				for (key, item) in &o.0 {
+					estimator.add_element()?;
+					estimator.add_string(key.len())?;
+					item.accumulate_size(estimator)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl ops::Add for Value {
+	type Output = Result<Value, Error>;
+
+	/// Implements string concatenation (`a + b`), checked against `SURREAL_MAX_STRING_LENGTH`
+	/// and `SURREAL_MAX_VALUE_SIZE` before the concatenated string is allocated.
+	fn add(self, other: Value) -> Result<Value, Error> {
+		match (self, other) {
+			(Value::Strand(a), Value::Strand(b)) => {
+				let len = a.as_str().len() + b.as_str().len();
+				cnf::check_string_length(len)?;
+				cnf::check_value_size(len)?;
+				let mut s = a.0;
+				s.push_str(b.as_str());
+				Ok(Value::Strand(Strand(s)))
+			}
+			(a, b) => Ok(Value::Array(Array(vec![a, b]))),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn strand_literal_builds_a_strand() {
+		let v = Value::strand_literal("hello".to_owned()).unwrap();
+		assert_eq!(v, Value::Strand(Strand("hello".to_owned())));
+	}
+
+	#[test]
+	fn concatenation_joins_the_strings() {
+		let a = Value::Strand(Strand("foo".to_owned()));
+		let b = Value::Strand(Strand("bar".to_owned()));
+		let v = (a + b).unwrap();
+		assert_eq!(v, Value::Strand(Strand("foobar".to_owned())));
+	}
+
+	#[test]
+	fn accumulate_size_recurses_into_nested_values() {
+		let nested = Value::Array(Array(vec![
+			Value::Strand(Strand("hi".to_owned())),
+			Value::Object(Object(std::collections::BTreeMap::from([(
+				"k".to_owned(),
+				Value::Strand(Strand("there".to_owned())),
+			)]))),
+		]));
+		let mut estimator = cnf::ValueSizeEstimator::new();
+		nested.accumulate_size(&mut estimator).unwrap();
+		assert!(estimator.total() > 0);
+	}
+}
@@ -0,0 +1,19 @@
+use crate::sql::datetime::{Datetime, Zone};
+use chrono::{DateTime, Utc};
+
+/// Parses a SurrealQL datetime literal, e.g. `2023-05-01T12:30:00Z` or
+/// `2023-05-01T12:30:00+09:00`, retaining the parsed offset instead of normalizing it away, so
+/// that round-tripping the literal back through `Datetime::to_raw`/`Display` reproduces the zone
+/// the user wrote.
+pub fn datetime(v: &str) -> Result<Datetime, ()> {
+	let parsed = DateTime::parse_from_rfc3339(v).map_err(|_| ())?;
+	// A zero offset is ambiguous between an explicit `+00:00` and a bare `Z`; treat it as the
+	// latter so a plain UTC literal keeps round-tripping as `Z`, matching
+	// `Datetime`'s `TryFrom<&str>` impl.
+	let zone = if parsed.offset().local_minus_utc() == 0 {
+		None
+	} else {
+		Some(Zone::Fixed(*parsed.offset()))
+	};
+	Ok(Datetime(parsed.with_timezone(&Utc), zone))
+}